@@ -31,6 +31,8 @@
 //! - NWEP protocol negotiation (ALPN: nwep/1)
 //! - NWEP methods (READ instead of GET)
 //! - Text status tokens (ok, not_found, etc.)
+//! - Issuing multiple requests concurrently over a single connection
+//! - `--subscribe` for a live fragmented-MP4 object stream (SUBSCRIBE)
 //!
 //! Based on the quiche client example.
 
@@ -41,49 +43,96 @@ use quiche::h3::NameValue;
 
 use ring::rand::*;
 
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
 use std::net::ToSocketAddrs;
+use std::path::PathBuf;
 
 const MAX_DATAGRAM_SIZE: usize = 1350;
 
-fn main() {
-    let mut buf = [0; 65535];
-    let mut out = [0; MAX_DATAGRAM_SIZE];
+/// State for a single in-flight NWEP request, keyed by its stream id.
+struct Request {
+    authority: String,
+    path: String,
 
-    let mut args = std::env::args();
+    start: std::time::Instant,
 
-    let cmd = &args.next().unwrap();
+    /// Where to write the response body. `None` means stdout.
+    dump_file: Option<fs::File>,
 
-    if args.len() != 1 {
-        println!("Usage: {cmd} URL");
-        println!("\nSee tools/apps/ for more complete implementations.");
-        return;
+    finished: bool,
+}
+
+impl Request {
+    fn new(
+        authority: &str, path: &str, dump_path: Option<&PathBuf>,
+    ) -> Self {
+        let dump_file = dump_path.map(|dir| {
+            let out_path = dir.join(format!("{authority}{path}"));
+
+            if let Some(parent) = out_path.parent() {
+                fs::create_dir_all(parent).unwrap();
+            }
+
+            fs::File::create(&out_path).unwrap_or_else(|e| {
+                panic!("failed to create {:?}: {e:?}", out_path)
+            })
+        });
+
+        Request {
+            authority: authority.to_string(),
+            path: path.to_string(),
+            start: std::time::Instant::now(),
+            dump_file,
+            finished: false,
+        }
     }
 
-    // Parse URL - NWEP uses web:// scheme only (no HTTPS!)
-    let url_str = args.next().unwrap();
+    fn write_body(&mut self, buf: &[u8]) {
+        match &mut self.dump_file {
+            Some(file) => file.write_all(buf).unwrap(),
 
-    // Silently reject non-web:// schemes
+            None => print!("{}", unsafe {
+                std::str::from_utf8_unchecked(buf)
+            }),
+        }
+    }
+}
+
+/// Split a `web://host[:port]/path` URL into its authority and path parts.
+///
+/// Manual parsing since the `url` crate doesn't recognize the `web://`
+/// scheme.
+fn parse_url(url_str: &str) -> Option<(String, String)> {
     if !url_str.starts_with("web://") {
-        std::process::exit(1);
+        return None;
     }
 
-    // Manual parsing since url crate doesn't recognize web://
-    // Format: web://host[:port]/path
-    let without_scheme = &url_str[6..]; // Remove "web://"
+    let without_scheme = &url_str[6..];
 
-    let (authority, path) = if let Some(slash_pos) = without_scheme.find('/') {
+    let (authority, path) = if let Some(slash_pos) = without_scheme.find('/')
+    {
         (&without_scheme[..slash_pos], &without_scheme[slash_pos..])
     } else {
         (without_scheme, "/")
     };
 
-    let (host, port) = if let Some(colon_pos) = authority.rfind(':') {
+    Some((authority.to_string(), path.to_string()))
+}
+
+/// Split an authority (`host[:port]` or `[ipv6]:port`) into host and port.
+fn parse_authority(authority: &str) -> (&str, u16) {
+    if let Some(colon_pos) = authority.rfind(':') {
         // Check if this is IPv6 [::1]:port format
         if authority.starts_with('[') {
             if let Some(bracket_end) = authority.find(']') {
                 if bracket_end < colon_pos {
                     // IPv6 with port: [::1]:4433
-                    (&authority[..=bracket_end], authority[colon_pos+1..].parse::<u16>().unwrap_or(4433))
+                    (
+                        &authority[..=bracket_end],
+                        authority[colon_pos + 1..].parse::<u16>().unwrap_or(4433),
+                    )
                 } else {
                     // IPv6 without port: [::1]
                     (authority, 4433)
@@ -93,11 +142,275 @@ fn main() {
             }
         } else {
             // Regular host:port
-            (&authority[..colon_pos], authority[colon_pos+1..].parse::<u16>().unwrap_or(4433))
+            (
+                &authority[..colon_pos],
+                authority[colon_pos + 1..].parse::<u16>().unwrap_or(4433),
+            )
         }
     } else {
         (authority, 4433)
-    };
+    }
+}
+
+/// Sends `payload` unreliably: as a QUIC DATAGRAM when it fits the
+/// connection's current datagram capacity, falling back to a dedicated
+/// client-initiated unidirectional stream when it doesn't. Used for small,
+/// latency-critical payloads that are fine to lose but shouldn't be held up
+/// behind a stream's flow control either.
+fn send_unreliable(
+    conn: &mut quiche::Connection, next_uni_stream_id: &mut u64, payload: &[u8],
+) {
+    let fits_dgram = conn
+        .dgram_max_writable_len()
+        .is_some_and(|max_len| payload.len() <= max_len);
+
+    if fits_dgram {
+        if let Err(e) = conn.dgram_send(payload.to_vec()) {
+            warn!("failed to send datagram, dropping payload: {e:?}");
+        }
+
+        return;
+    }
+
+    let stream_id = *next_uni_stream_id;
+    *next_uni_stream_id += 4;
+
+    if let Err(e) = conn.stream_send(stream_id, payload, true) {
+        warn!("failed to send fallback stream {stream_id}: {e:?}");
+    }
+}
+
+/// Marks a unidirectional stream (or datagram) as carrying a NWEP fragment,
+/// as opposed to h3/QPACK's own reserved uni streams (control, and the QPACK
+/// encoder/decoder streams, types `0x00`-`0x03`). The raw fragment scan below
+/// only ever acts on bytes led by this byte, so it can't be fooled into
+/// parsing an `ObjectHeader` out of h3 infrastructure traffic.
+const NWEP_FRAGMENT_STREAM_TYPE: u8 = 0x66;
+
+/// Header prepended to every fragment stream of a `SUBSCRIBE` object stream,
+/// so the client can reorder fragments delivered out of order.
+struct ObjectHeader {
+    group_id: u64,
+    object_id: u64,
+}
+
+impl ObjectHeader {
+    const LEN: usize = 16;
+
+    fn parse(buf: &[u8]) -> Option<(Self, &[u8])> {
+        if buf.len() < Self::LEN {
+            return None;
+        }
+
+        let group_id = u64::from_be_bytes(buf[0..8].try_into().unwrap());
+        let object_id = u64::from_be_bytes(buf[8..16].try_into().unwrap());
+
+        Some((ObjectHeader { group_id, object_id }, &buf[Self::LEN..]))
+    }
+}
+
+/// Reassembles a `SUBSCRIBE` media object stream out of the one-fragment-
+/// per-QUIC-stream delivery described in the NWEP media subscription mode:
+/// the init segment arrives on its own stream, then each fragment arrives
+/// on a separate stream tagged with an `ObjectHeader`.
+struct ObjectReassembly {
+    /// Raw bytes buffered per stream id until that stream reaches fin.
+    pending: HashMap<u64, Vec<u8>>,
+
+    /// Completed fragments, keyed by `(group_id, object_id)`, waiting for
+    /// their turn to be emitted in ascending order.
+    ready: std::collections::BTreeMap<(u64, u64), Vec<u8>>,
+
+    /// Highest group id already emitted. Fragments from an older group are
+    /// stale -- useless for live playback -- and are dropped on arrival.
+    rendered_group: Option<u64>,
+
+    /// `(group_id, object_id)` of the last fragment handed to `emit`, used
+    /// to drop a fragment that arrives behind one already emitted.
+    last_emitted: Option<(u64, u64)>,
+
+    /// Stream id carrying the reliable init segment; the request/response
+    /// path already handles that stream, so fragment reassembly skips it.
+    init_stream: u64,
+}
+
+impl ObjectReassembly {
+    fn new(init_stream: u64) -> Self {
+        ObjectReassembly {
+            pending: HashMap::new(),
+            ready: std::collections::BTreeMap::new(),
+            rendered_group: None,
+            last_emitted: None,
+            init_stream,
+        }
+    }
+
+    /// Feed newly-received fragment bytes for `stream_id`. `fin` marks the
+    /// stream as complete, at which point the buffered bytes become a
+    /// fragment ready to be ordered.
+    fn recv(&mut self, stream_id: u64, data: &[u8], fin: bool) {
+        self.pending.entry(stream_id).or_default().extend_from_slice(data);
+
+        if !fin {
+            return;
+        }
+
+        let buf = self.pending.remove(&stream_id).unwrap_or_default();
+
+        self.ingest_fragment(&buf);
+    }
+
+    /// Feed one complete fragment delivered as an unreliable QUIC datagram;
+    /// unlike a stream, a datagram is always either fully present or lost,
+    /// so there is nothing to buffer.
+    fn recv_dgram(&mut self, data: &[u8]) {
+        self.ingest_fragment(data);
+    }
+
+    fn ingest_fragment(&mut self, buf: &[u8]) {
+        let Some((&stream_type, buf)) = buf.split_first() else {
+            warn!("fragment too short for stream type marker");
+            return;
+        };
+
+        if stream_type != NWEP_FRAGMENT_STREAM_TYPE {
+            warn!(
+                "dropping data with unexpected stream type {stream_type:#x}, \
+                 not a NWEP fragment"
+            );
+
+            return;
+        }
+
+        match ObjectHeader::parse(buf) {
+            Some((hdr, payload)) => {
+                if Some(hdr.group_id) < self.rendered_group {
+                    debug!(
+                        "dropping stale fragment group={} object={}",
+                        hdr.group_id, hdr.object_id
+                    );
+
+                    return;
+                }
+
+                self.ready
+                    .insert((hdr.group_id, hdr.object_id), payload.to_vec());
+            },
+
+            None => warn!("fragment too short for object header"),
+        }
+    }
+
+    /// Drain fragments in ascending `(group_id, object_id)` order: a
+    /// fragment is only emitted once its key is strictly greater than the
+    /// last one emitted. A gap left by a lost fragment is skipped rather
+    /// than blocking later, already-received fragments (including entire
+    /// later groups) forever -- but a fragment that arrives out of order
+    /// behind one already emitted is never re-surfaced ahead of it.
+    fn drain_ready(&mut self, mut emit: impl FnMut(u64, u64, &[u8])) {
+        loop {
+            let Some(&(group_id, object_id)) =
+                self.ready.keys().next()
+            else {
+                return;
+            };
+
+            let is_next = self
+                .last_emitted
+                .is_none_or(|last| (group_id, object_id) > last);
+
+            let payload = self.ready.remove(&(group_id, object_id)).unwrap();
+
+            if !is_next {
+                debug!(
+                    "dropping late fragment group={group_id} object={object_id}"
+                );
+
+                continue;
+            }
+
+            emit(group_id, object_id, &payload);
+
+            self.last_emitted = Some((group_id, object_id));
+            self.rendered_group = Some(group_id);
+        }
+    }
+}
+
+fn main() {
+    let mut buf = [0; 65535];
+    let mut out = [0; MAX_DATAGRAM_SIZE];
+
+    let mut args = std::env::args();
+
+    let cmd = &args.next().unwrap();
+
+    let mut dump_path = None;
+    let mut session_file = None;
+    let mut qlog_dir = None;
+    let mut subscribe = false;
+    let mut urls = Vec::new();
+
+    let mut arg = args.next();
+
+    while let Some(a) = arg {
+        if a == "--subscribe" {
+            subscribe = true;
+        } else if a == "--dump-path" {
+            let dir = args.next().unwrap_or_else(|| {
+                println!("--dump-path requires a directory argument");
+                std::process::exit(1);
+            });
+
+            dump_path = Some(PathBuf::from(dir));
+        } else if a == "--session-file" {
+            let path = args.next().unwrap_or_else(|| {
+                println!("--session-file requires a path argument");
+                std::process::exit(1);
+            });
+
+            session_file = Some(PathBuf::from(path));
+        } else if a == "--qlog-dir" {
+            let dir = args.next().unwrap_or_else(|| {
+                println!("--qlog-dir requires a directory argument");
+                std::process::exit(1);
+            });
+
+            qlog_dir = Some(PathBuf::from(dir));
+        } else {
+            urls.push(a);
+        }
+
+        arg = args.next();
+    }
+
+    if urls.is_empty() {
+        println!(
+            "Usage: {cmd} [--dump-path DIR] [--session-file PATH] [--qlog-dir DIR] [--subscribe] URL..."
+        );
+        println!("\nSee tools/apps/ for more complete implementations.");
+        return;
+    }
+
+    if subscribe && urls.len() > 1 {
+        println!("--subscribe only supports a single URL");
+        std::process::exit(1);
+    }
+
+    // Parse every URL up front; all of them are expected to share the same
+    // authority since they're issued over a single QUIC connection.
+    let parsed: Vec<(String, String)> = urls
+        .iter()
+        .map(|url_str| {
+            parse_url(url_str).unwrap_or_else(|| {
+                // Silently reject non-web:// schemes
+                std::process::exit(1);
+            })
+        })
+        .collect();
+
+    let (authority, _) = &parsed[0];
+    let (host, port) = parse_authority(authority);
 
     let host_str = host.trim_start_matches('[').trim_end_matches(']');
 
@@ -106,7 +419,7 @@ fn main() {
     let mut events = mio::Events::with_capacity(1024);
 
     // Resolve server address from our parsed web:// URL
-    let peer_addr = format!("{}:{}", host_str, port)
+    let peer_addr = format!("{host_str}:{port}")
         .parse::<std::net::SocketAddr>()
         .or_else(|_| {
             // Try DNS resolution
@@ -159,6 +472,31 @@ fn main() {
     config.set_initial_max_streams_uni(100);
     config.set_disable_active_migration(true);
 
+    // Allow sending the NWEP request as early data on a resumed connection.
+    config.enable_early_data();
+
+    // Small, latency-critical payloads (e.g. SUBSCRIBE fragments under the
+    // MTU) travel as unreliable QUIC DATAGRAMs instead of stream data, so
+    // loss never stalls waiting for a retransmit.
+    const DGRAM_QUEUE_LEN: usize = 1000;
+    config.enable_dgram(true, DGRAM_QUEUE_LEN, DGRAM_QUEUE_LEN);
+
+    // If requested, log the TLS traffic secrets so captured packets can be
+    // decrypted in Wireshark.
+    let mut keylog = None;
+
+    if let Some(keylog_path) = std::env::var_os("SSLKEYLOGFILE") {
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(keylog_path)
+            .unwrap();
+
+        keylog = Some(file);
+
+        config.log_keys();
+    }
+
     let mut nwep_conn = None;
 
     // Generate a random source connection ID for the connection.
@@ -176,6 +514,43 @@ fn main() {
         quiche::connect(Some(host_str), &scid, local_addr, peer_addr, &mut config)
             .unwrap();
 
+    if let Some(keylog) = &keylog {
+        if let Ok(keylog) = keylog.try_clone() {
+            conn.set_keylog(Box::new(keylog));
+        }
+    }
+
+    // Capture the full handshake, packet, and recovery event stream, named
+    // after the connection's SCID. Since NWEP uses a custom ALPN and the
+    // READ method, this is the only practical way to debug why a server
+    // rejected a stream.
+    if let Some(qlog_dir) = &qlog_dir {
+        let qlog_path = qlog_dir.join(format!("{}.qlog", hex_dump(&scid)));
+
+        let qlog_file = fs::File::create(&qlog_path).unwrap_or_else(|e| {
+            panic!("failed to create {:?}: {e:?}", qlog_path)
+        });
+
+        conn.set_qlog(
+            Box::new(qlog_file),
+            "nwep-client qlog".to_string(),
+            format!("nwep-client qlog id={}", hex_dump(&scid)),
+        );
+    }
+
+    // If we have a saved session ticket from a previous connection to this
+    // authority, offer it so the handshake can resume and the READ request
+    // can go out as 0-RTT early data.
+    if let Some(session_file) = &session_file {
+        if let Ok(session) = fs::read(session_file) {
+            if let Err(e) = conn.set_session(&session) {
+                warn!("unable to set session, full handshake will be used: {e:?}");
+            }
+        }
+    }
+
+    let mut session_saved = false;
+
     info!(
         "connecting to {:} from {:} with scid {}",
         peer_addr,
@@ -198,19 +573,23 @@ fn main() {
 
     let nwep_config = quiche::h3::Config::new().unwrap();
 
-    // NWEP request using READ method and web:// scheme
-    // No url crate - we parsed it manually above!
-    let req = vec![
-        quiche::h3::Header::new(b":method", b"READ"),
-        quiche::h3::Header::new(b":scheme", b"web"),
-        quiche::h3::Header::new(b":authority", authority.as_bytes()),
-        quiche::h3::Header::new(b":path", path.as_bytes()),
-        quiche::h3::Header::new(b"user-agent", b"nwep-client"),
-    ];
+    // Pending requests, keyed by the stream id that `send_request` hands
+    // back, plus whether every one of them has been issued yet.
+    let mut reqs: HashMap<u64, Request> = HashMap::new();
+    let mut next_req = 0;
 
-    let req_start = std::time::Instant::now();
+    // Set once the SUBSCRIBE request has gone out; the init segment arrives
+    // on its own stream reassembly tracks from that point on.
+    let mut subscription: Option<ObjectReassembly> = None;
 
-    let mut req_sent = false;
+    // Next client-initiated unidirectional stream id `send_unreliable` will
+    // use if a payload doesn't fit in a DATAGRAM. Client-initiated uni
+    // streams start at 2 and increment by 4, but `with_transport` has
+    // already claimed the first three of them (ids 2, 6, 10) for h3's own
+    // control stream and QPACK encoder/decoder streams, so the fallback
+    // path has to start past those or it corrupts h3's own streams.
+    const H3_CLIENT_RESERVED_UNI_STREAMS: u64 = 3;
+    let mut next_uni_stream_id: u64 = 2 + H3_CLIENT_RESERVED_UNI_STREAMS * 4;
 
     loop {
         poll.poll(&mut events, conn.timeout()).unwrap();
@@ -271,23 +650,80 @@ fn main() {
             break;
         }
 
-        // Create a new NWEP connection once the QUIC connection is established.
-        if conn.is_established() && nwep_conn.is_none() {
+        // Create a new NWEP connection once the QUIC connection is
+        // established, or as soon as it is willing to carry early data on a
+        // resumed session, so the READ request can skip a round trip.
+        if (conn.is_established() || conn.is_in_early_data()) &&
+            nwep_conn.is_none()
+        {
             nwep_conn = Some(
                 quiche::h3::Connection::with_transport(&mut conn, &nwep_config)
                 .expect("Unable to create NWEP connection, check the server's uni stream limit and window size"),
             );
         }
 
-        // Send NWEP requests once the QUIC connection is established, and until
-        // all requests have been sent.
+        // Once the handshake completes, stash the (possibly new) session
+        // ticket so the next run against this authority can resume. quiche
+        // only hands out the ticket once it arrives from the peer, which is
+        // typically a short while *after* the handshake finishes, so keep
+        // polling `conn.session()` on every established iteration until it
+        // actually produces one instead of giving up after the first try.
+        if conn.is_established() && !session_saved {
+            if let Some(session_file) = &session_file {
+                if let Some(session) = conn.session() {
+                    fs::write(session_file, session).unwrap_or_else(|e| {
+                        warn!("failed to write session file: {e:?}");
+                    });
+
+                    session_saved = true;
+                }
+            } else {
+                session_saved = true;
+            }
+        }
+
+        // Issue every outstanding request once the NWEP connection is ready,
+        // each on its own stream so they're multiplexed over the one QUIC
+        // connection.
         if let Some(nwep_conn_ref) = &mut nwep_conn {
-            if !req_sent {
+            while next_req < parsed.len() {
+                let (authority, path) = &parsed[next_req];
+
+                let method: &[u8] =
+                    if subscribe { b"SUBSCRIBE" } else { b"READ" };
+
+                let req = vec![
+                    quiche::h3::Header::new(b":method", method),
+                    quiche::h3::Header::new(b":scheme", b"web"),
+                    quiche::h3::Header::new(b":authority", authority.as_bytes()),
+                    quiche::h3::Header::new(b":path", path.as_bytes()),
+                    quiche::h3::Header::new(b"user-agent", b"nwep-client"),
+                ];
+
                 info!("sending NWEP request {req:?}");
 
-                nwep_conn_ref.send_request(&mut conn, &req, true).unwrap();
+                let stream_id =
+                    match nwep_conn_ref.send_request(&mut conn, &req, true) {
+                        Ok(v) => v,
+
+                        Err(quiche::h3::Error::StreamBlocked) => break,
+
+                        Err(e) => panic!("request send failed: {e:?}"),
+                    };
+
+                if subscribe {
+                    // The request stream itself carries the reliable init
+                    // segment; every later fragment arrives on a stream of
+                    // its own, picked up below via `conn.readable()`.
+                    subscription = Some(ObjectReassembly::new(stream_id));
+                }
+
+                reqs.insert(
+                    stream_id,
+                    Request::new(authority, path, dump_path.as_ref()),
+                );
 
-                req_sent = true;
+                next_req += 1;
             }
         }
 
@@ -302,7 +738,13 @@ fn main() {
                         for hdr in &list {
                             if hdr.name() == b":status" {
                                 let status = String::from_utf8_lossy(hdr.value());
-                                println!("{}", status);
+
+                                if let Some(req) = reqs.get(&stream_id) {
+                                    println!("{} {}{}", status, req.authority, req.path);
+                                } else {
+                                    println!("{status}");
+                                }
+
                                 break;
                             }
                         }
@@ -322,25 +764,62 @@ fn main() {
                                 "got {read} bytes of response data on stream {stream_id}"
                             );
 
-                            print!("{}", unsafe {
-                                std::str::from_utf8_unchecked(&buf[..read])
-                            });
+                            if let Some(req) = reqs.get_mut(&stream_id) {
+                                req.write_body(&buf[..read]);
+                            }
                         }
                     },
 
-                    Ok((_stream_id, quiche::h3::Event::Finished)) => {
-                        info!(
-                            "response received in {:?}, closing...",
-                            req_start.elapsed()
-                        );
+                    Ok((stream_id, quiche::h3::Event::Finished)) => {
+                        if let Some(req) = reqs.get_mut(&stream_id) {
+                            info!(
+                                "response on stream {} received in {:?}",
+                                stream_id,
+                                req.start.elapsed()
+                            );
+
+                            req.finished = true;
+                        }
 
-                        conn.close(true, 0x100, b"kthxbye").unwrap();
+                        // A SUBSCRIBE response never reaches `Finished` on
+                        // its own terms -- the server keeps delivering
+                        // fragments on other streams after the init segment
+                        // lands, until the client resets it -- so the init
+                        // stream finishing must not trip auto-close by
+                        // itself; only an explicit Reset does.
+                        let is_subscription_init_stream = subscription
+                            .as_ref()
+                            .is_some_and(|sub| sub.init_stream == stream_id);
+
+                        if !is_subscription_init_stream &&
+                            next_req >= parsed.len() &&
+                            reqs.values().all(|r| r.finished)
+                        {
+                            info!("all requests finished, closing...");
+
+                            conn.close(true, 0x100, b"kthxbye").unwrap();
+                        }
                     },
 
-                    Ok((_stream_id, quiche::h3::Event::Reset(e))) => {
-                        error!("request was reset by peer with {e}, closing...");
+                    Ok((stream_id, quiche::h3::Event::Reset(e))) => {
+                        // A reset here can also mean the server declined our
+                        // 0-RTT request; quiche transparently retransmits
+                        // early data once the full handshake completes, so
+                        // this only fires if the request genuinely failed
+                        // after that fallback.
+                        error!(
+                            "request on stream {stream_id} was reset by peer with {e}"
+                        );
+
+                        if let Some(req) = reqs.get_mut(&stream_id) {
+                            req.finished = true;
+                        }
 
-                        conn.close(true, 0x100, b"kthxbye").unwrap();
+                        if next_req >= parsed.len() &&
+                            reqs.values().all(|r| r.finished)
+                        {
+                            conn.close(true, 0x100, b"kthxbye").unwrap();
+                        }
                     },
 
                     Ok((_, quiche::h3::Event::PriorityUpdate)) => unreachable!(),
@@ -362,6 +841,83 @@ fn main() {
             }
         }
 
+        // Pick up fragment streams the server opened outside of the
+        // SUBSCRIBE request/response exchange, reassemble them, and emit
+        // completed objects in ascending (group_id, object_id) order. This
+        // runs *after* NWEP event processing above, and is restricted to
+        // server-initiated unidirectional stream ids (id % 4 == 3) past the
+        // first three, which the server's h3 layer claims (ids 3, 7, 11), so
+        // it never steals bytes belonging to h3/QPACK's own uni streams (the
+        // control stream, or the QPACK encoder/decoder streams) or to any
+        // bidirectional request/response stream.
+        const H3_SERVER_RESERVED_UNI_STREAMS: u64 = 3;
+
+        if let Some(sub) = &mut subscription {
+            for readable_id in conn.readable() {
+                if readable_id == sub.init_stream {
+                    continue;
+                }
+
+                if readable_id % 4 != 3 ||
+                    readable_id < 3 + H3_SERVER_RESERVED_UNI_STREAMS * 4
+                {
+                    continue;
+                }
+
+                loop {
+                    match conn.stream_recv(readable_id, &mut buf) {
+                        Ok((len, fin)) => {
+                            sub.recv(readable_id, &buf[..len], fin);
+
+                            if fin {
+                                break;
+                            }
+                        },
+
+                        Err(quiche::Error::Done) => break,
+
+                        Err(e) => {
+                            error!(
+                                "fragment stream {readable_id} failed: {e:?}"
+                            );
+                            break;
+                        },
+                    }
+                }
+            }
+
+            // Fragments small enough to fit a DATAGRAM may also arrive that
+            // way; fold them into the same ordered-delivery path.
+            while let Ok(len) = conn.dgram_recv(&mut buf) {
+                sub.recv_dgram(&buf[..len]);
+            }
+
+            let init_stream = sub.init_stream;
+
+            sub.drain_ready(|group_id, object_id, payload| {
+                info!(
+                    "emitting object group={group_id} object={object_id} ({} bytes)",
+                    payload.len()
+                );
+
+                if let Some(req) = reqs.get_mut(&init_stream) {
+                    req.write_body(payload);
+                }
+
+                // Let the server know playback has caught up to this
+                // object. This is pure telemetry -- losing one doesn't
+                // affect delivery -- so it goes out unreliably, falling
+                // back to a stream only if it's ever too big for a
+                // DATAGRAM.
+                let ack = format!("ack {group_id} {object_id}");
+                send_unreliable(
+                    &mut conn,
+                    &mut next_uni_stream_id,
+                    ack.as_bytes(),
+                );
+            });
+        }
+
         // Generate outgoing QUIC packets and send them on the UDP socket, until
         // quiche reports that there are no more packets to be sent.
         loop {
@@ -72,6 +72,15 @@ pub enum Method {
     ///
     /// Safe: Yes, Idempotent: Yes, Cacheable: No
     Trace,
+
+    /// Subscribe to a continuously produced resource (no HTTP equivalent).
+    ///
+    /// Unlike `READ`, a `SUBSCRIBE` response never reaches `Finished` on its
+    /// own terms: the server keeps delivering fragments of the subscribed
+    /// resource, each on its own stream, until the client resets it.
+    ///
+    /// Safe: Yes, Idempotent: No, Cacheable: No
+    Subscribe,
 }
 
 impl Method {
@@ -87,6 +96,7 @@ impl Method {
             b"PROBE" => Ok(Method::Probe),
             b"CONNECT" => Ok(Method::Connect),
             b"TRACE" => Ok(Method::Trace),
+            b"SUBSCRIBE" => Ok(Method::Subscribe),
             _ => Err(Error::MessageError),
         }
     }
@@ -101,14 +111,18 @@ impl Method {
             Method::Probe => b"PROBE",
             Method::Connect => b"CONNECT",
             Method::Trace => b"TRACE",
+            Method::Subscribe => b"SUBSCRIBE",
         }
     }
 
     /// Check if method is safe (does not modify state).
     ///
-    /// Safe methods: READ, PROBE, TRACE
+    /// Safe methods: READ, PROBE, TRACE, SUBSCRIBE
     pub fn is_safe(&self) -> bool {
-        matches!(self, Method::Read | Method::Probe | Method::Trace)
+        matches!(
+            self,
+            Method::Read | Method::Probe | Method::Trace | Method::Subscribe
+        )
     }
 
     /// Check if method is idempotent (can be retried).
@@ -135,35 +149,91 @@ impl Method {
     /// Convert from HTTP method for gateway compatibility.
     ///
     /// Returns `None` if the HTTP method doesn't map to a NWEP method.
+    ///
+    /// Folds GET/HEAD into `Read` and POST/PUT into `Write`, same as
+    /// before; pair this with [`Method::from_http_method_with_hint`] when
+    /// the distinction needs to survive the round trip.
     pub fn from_http_method(method: &[u8]) -> Option<Self> {
+        Self::from_http_method_with_hint(method).map(|(m, _)| m)
+    }
+
+    /// Convert from HTTP method for gateway compatibility, also returning
+    /// which specific HTTP method was folded into the result.
+    ///
+    /// `Method::Read` covers both GET and HEAD, and `Method::Write` covers
+    /// both POST and PUT; the returned [`HttpMethodHint`] records which
+    /// one it actually was, so [`Method::to_http_method_with_hint`] can
+    /// reconstruct it instead of always emitting the most common
+    /// equivalent.
+    pub fn from_http_method_with_hint(
+        method: &[u8],
+    ) -> Option<(Self, HttpMethodHint)> {
         match method {
-            b"GET" | b"HEAD" => Some(Method::Read),
-            b"POST" | b"PUT" => Some(Method::Write),
-            b"PATCH" => Some(Method::Modify),
-            b"DELETE" => Some(Method::Delete),
-            b"OPTIONS" => Some(Method::Probe),
-            b"CONNECT" => Some(Method::Connect),
-            b"TRACE" => Some(Method::Trace),
+            b"GET" => Some((Method::Read, HttpMethodHint::Default)),
+            b"HEAD" => Some((Method::Read, HttpMethodHint::Head)),
+            b"POST" => Some((Method::Write, HttpMethodHint::Default)),
+            b"PUT" => Some((Method::Write, HttpMethodHint::Put)),
+            b"PATCH" => Some((Method::Modify, HttpMethodHint::Default)),
+            b"DELETE" => Some((Method::Delete, HttpMethodHint::Default)),
+            b"OPTIONS" => Some((Method::Probe, HttpMethodHint::Default)),
+            b"CONNECT" => Some((Method::Connect, HttpMethodHint::Default)),
+            b"TRACE" => Some((Method::Trace, HttpMethodHint::Default)),
             _ => None,
         }
     }
 
     /// Convert to HTTP method for gateway compatibility.
     ///
-    /// Returns the most common HTTP equivalent.
+    /// Returns the most common HTTP equivalent. `SUBSCRIBE` has none, so a
+    /// gateway should treat it as a long-lived `GET`. Use
+    /// [`Method::to_http_method_with_hint`] to recover the exact original
+    /// method instead.
     pub fn to_http_method(&self) -> &'static [u8] {
-        match self {
-            Method::Read => b"GET",
-            Method::Write => b"POST",
-            Method::Modify => b"PATCH",
-            Method::Delete => b"DELETE",
-            Method::Probe => b"OPTIONS",
-            Method::Connect => b"CONNECT",
-            Method::Trace => b"TRACE",
+        self.to_http_method_with_hint(HttpMethodHint::Default)
+    }
+
+    /// Convert to HTTP method for gateway compatibility, using `hint` to
+    /// reconstruct the exact original method rather than the most common
+    /// equivalent.
+    ///
+    /// A `hint` that doesn't apply to `self` (e.g. `HttpMethodHint::Put`
+    /// with `Method::Read`) is ignored.
+    pub fn to_http_method_with_hint(
+        &self, hint: HttpMethodHint,
+    ) -> &'static [u8] {
+        match (self, hint) {
+            (Method::Read, HttpMethodHint::Head) => b"HEAD",
+            (Method::Write, HttpMethodHint::Put) => b"PUT",
+            (Method::Read, _) => b"GET",
+            (Method::Write, _) => b"POST",
+            (Method::Modify, _) => b"PATCH",
+            (Method::Delete, _) => b"DELETE",
+            (Method::Probe, _) => b"OPTIONS",
+            (Method::Connect, _) => b"CONNECT",
+            (Method::Trace, _) => b"TRACE",
+            (Method::Subscribe, _) => b"GET",
         }
     }
 }
 
+/// Which specific HTTP method a NWEP [`Method`] was translated from,
+/// carried alongside it so a gateway can translate back faithfully
+/// instead of always re-emitting the most common equivalent.
+///
+/// Only `Method::Read` (GET/HEAD) and `Method::Write` (POST/PUT) are
+/// actually ambiguous today; every other NWEP method maps to exactly one
+/// HTTP method, so `Default` is the only hint that applies to them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HttpMethodHint {
+    /// No ambiguity, or no hint available: use the most common
+    /// equivalent.
+    Default,
+    /// `Method::Read` came from a `HEAD` request, not `GET`.
+    Head,
+    /// `Method::Write` came from a `PUT` request, not `POST`.
+    Put,
+}
+
 /// NWEP response status tokens.
 ///
 /// NWEP uses human-readable text tokens instead of numeric HTTP status codes.
@@ -234,12 +304,83 @@ pub enum StatusToken {
     ServiceUnavailable,
     /// Upstream timeout (HTTP 504).
     GatewayTimeout,
+
+    /// An unrecognized token, preserved verbatim.
+    ///
+    /// Lets a gateway round-trip a peer's status token instead of
+    /// collapsing it to `InternalError`, and still lets callers reason
+    /// about it by class via [`ExtToken::class`].
+    Extension(ExtToken),
+}
+
+/// A preserved unrecognized status token, inlined so [`StatusToken`] can
+/// stay `Copy`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExtToken {
+    bytes: [u8; ExtToken::MAX_LEN],
+    len: u8,
+    class: StatusClass,
+}
+
+impl ExtToken {
+    const MAX_LEN: usize = 32;
+
+    /// Build an `ExtToken` from raw bytes, inferring its class from a
+    /// leading `<class>_` keyword (`informational_`, `success_`,
+    /// `redirect_`, `client_`, `server_`) or a leading numeric hint (e.g.
+    /// `499_custom`). Returns `None` if `token` is longer than the inline
+    /// buffer can hold.
+    fn new(token: &[u8]) -> Option<Self> {
+        if token.len() > Self::MAX_LEN {
+            return None;
+        }
+
+        let mut bytes = [0u8; Self::MAX_LEN];
+        bytes[..token.len()].copy_from_slice(token);
+
+        Some(ExtToken {
+            bytes,
+            len: token.len() as u8,
+            class: StatusClass::infer(token),
+        })
+    }
+
+    /// The preserved, wire-format token bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+
+    /// The status class inferred when this token was parsed.
+    pub fn class(&self) -> StatusClass {
+        self.class
+    }
+
+    /// The original HTTP status code this token carries, if its bytes
+    /// start with a numeric hint (e.g. `499_custom` -> `499`).
+    ///
+    /// Lets [`StatusToken::to_http_code`] hand back the exact code an
+    /// unmapped response came in with, instead of only its class default,
+    /// so a gateway round-trips a code like 499 faithfully.
+    fn http_code_hint(&self) -> Option<u16> {
+        let bytes = self.as_bytes();
+        let digits_end = bytes
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(bytes.len());
+
+        if digits_end == 0 {
+            return None;
+        }
+
+        std::str::from_utf8(&bytes[..digits_end]).ok()?.parse().ok()
+    }
 }
 
 impl StatusToken {
     /// Parse a status token from bytes.
     ///
-    /// Unknown tokens are treated as `InternalError` per NWEP spec.
+    /// An unrecognized token is preserved as `Extension` rather than
+    /// discarded, so a gateway can still forward it.
     pub fn from_bytes(token: &[u8]) -> Self {
         match token {
             b"continue" => StatusToken::Continue,
@@ -275,19 +416,24 @@ impl StatusToken {
             b"service_unavailable" => StatusToken::ServiceUnavailable,
             b"gateway_timeout" => StatusToken::GatewayTimeout,
 
-            // Unknown tokens default to internal_error
-            _ => {
-                warn!(
-                    "Unknown NWEP status token {:?}, treating as internal_error",
-                    std::str::from_utf8(token).unwrap_or("<invalid utf8>")
-                );
-                StatusToken::InternalError
+            _ => match ExtToken::new(token) {
+                Some(ext) => StatusToken::Extension(ext),
+
+                // Longer than the inline buffer can preserve; fall back to
+                // the old lossy behavior rather than truncating silently.
+                None => {
+                    warn!(
+                        "Unknown NWEP status token {:?} too long to preserve, treating as internal_error",
+                        std::str::from_utf8(token).unwrap_or("<invalid utf8>")
+                    );
+                    StatusToken::InternalError
+                },
             },
         }
     }
 
     /// Convert status token to bytes.
-    pub fn as_bytes(&self) -> &'static [u8] {
+    pub fn as_bytes(&self) -> &[u8] {
         match self {
             StatusToken::Continue => b"continue",
             StatusToken::SwitchingProtocols => b"switching_protocols",
@@ -321,10 +467,16 @@ impl StatusToken {
             StatusToken::BadGateway => b"bad_gateway",
             StatusToken::ServiceUnavailable => b"service_unavailable",
             StatusToken::GatewayTimeout => b"gateway_timeout",
+
+            StatusToken::Extension(ext) => ext.as_bytes(),
         }
     }
 
     /// Convert to HTTP status code for gateway compatibility.
+    ///
+    /// `Extension` tokens return the code carried by a leading numeric hint
+    /// (e.g. `499_custom` -> `499`), and otherwise the default code for
+    /// their inferred class (100/200/300/400/500).
     pub fn to_http_code(&self) -> u16 {
         match self {
             StatusToken::Continue => 100,
@@ -359,48 +511,64 @@ impl StatusToken {
             StatusToken::BadGateway => 502,
             StatusToken::ServiceUnavailable => 503,
             StatusToken::GatewayTimeout => 504,
+
+            StatusToken::Extension(ext) => ext
+                .http_code_hint()
+                .unwrap_or_else(|| ext.class().default_http_code()),
         }
     }
 
     /// Convert from HTTP status code for gateway compatibility.
     ///
-    /// Returns `None` if the code doesn't map to a defined NWEP token.
-    pub fn from_http_code(code: u16) -> Option<Self> {
+    /// Codes that don't map to a defined NWEP token synthesize an
+    /// `Extension` token carrying the original code (e.g.
+    /// `499_unmapped_client_error`)
+    /// rather than dropping the upstream status entirely, so
+    /// `to_http_code` can hand back the exact code later instead of only
+    /// its class default.
+    pub fn from_http_code(code: u16) -> Self {
         match code {
-            100 => Some(StatusToken::Continue),
-            101 => Some(StatusToken::SwitchingProtocols),
-
-            200 => Some(StatusToken::Ok),
-            201 => Some(StatusToken::Created),
-            202 => Some(StatusToken::Accepted),
-            204 => Some(StatusToken::NoContent),
-            206 => Some(StatusToken::PartialContent),
-
-            301 => Some(StatusToken::MovedPermanently),
-            302 => Some(StatusToken::Found),
-            303 => Some(StatusToken::SeeOther),
-            304 => Some(StatusToken::NotModified),
-            307 => Some(StatusToken::TemporaryRedirect),
-            308 => Some(StatusToken::PermanentRedirect),
-
-            400 => Some(StatusToken::BadRequest),
-            401 => Some(StatusToken::Unauthorized),
-            403 => Some(StatusToken::Forbidden),
-            404 => Some(StatusToken::NotFound),
-            405 => Some(StatusToken::MethodNotAllowed),
-            409 => Some(StatusToken::Conflict),
-            410 => Some(StatusToken::Gone),
-            413 => Some(StatusToken::PayloadTooLarge),
-            415 => Some(StatusToken::UnsupportedMediaType),
-            429 => Some(StatusToken::TooManyRequests),
-
-            500 => Some(StatusToken::InternalError),
-            501 => Some(StatusToken::NotImplemented),
-            502 => Some(StatusToken::BadGateway),
-            503 => Some(StatusToken::ServiceUnavailable),
-            504 => Some(StatusToken::GatewayTimeout),
+            100 => StatusToken::Continue,
+            101 => StatusToken::SwitchingProtocols,
 
-            _ => None,
+            200 => StatusToken::Ok,
+            201 => StatusToken::Created,
+            202 => StatusToken::Accepted,
+            204 => StatusToken::NoContent,
+            206 => StatusToken::PartialContent,
+
+            301 => StatusToken::MovedPermanently,
+            302 => StatusToken::Found,
+            303 => StatusToken::SeeOther,
+            304 => StatusToken::NotModified,
+            307 => StatusToken::TemporaryRedirect,
+            308 => StatusToken::PermanentRedirect,
+
+            400 => StatusToken::BadRequest,
+            401 => StatusToken::Unauthorized,
+            403 => StatusToken::Forbidden,
+            404 => StatusToken::NotFound,
+            405 => StatusToken::MethodNotAllowed,
+            409 => StatusToken::Conflict,
+            410 => StatusToken::Gone,
+            413 => StatusToken::PayloadTooLarge,
+            415 => StatusToken::UnsupportedMediaType,
+            429 => StatusToken::TooManyRequests,
+
+            500 => StatusToken::InternalError,
+            501 => StatusToken::NotImplemented,
+            502 => StatusToken::BadGateway,
+            503 => StatusToken::ServiceUnavailable,
+            504 => StatusToken::GatewayTimeout,
+
+            _ => {
+                let class = StatusClass::from_code(code);
+                let token = format!("{}_unmapped_{}", code, class.as_str());
+
+                // A formatted `<code>_unmapped_<class>` token always fits
+                // in `ExtToken`'s inline buffer.
+                StatusToken::Extension(ExtToken::new(token.as_bytes()).unwrap())
+            },
         }
     }
 
@@ -444,6 +612,716 @@ impl StatusClass {
             StatusClass::ServerError => "server_error",
         }
     }
+
+    /// Infer a class for an unrecognized status token.
+    ///
+    /// Looks for a leading `<class>_` keyword first (e.g. `client_quota_hit`
+    /// is a `ClientError`), then a leading HTTP-style digit (e.g.
+    /// `499_custom`), and otherwise defaults to `ServerError` since an
+    /// unrecognized token with no hint is safest treated as a failure.
+    fn infer(token: &[u8]) -> StatusClass {
+        for (prefix, class) in [
+            (&b"informational_"[..], StatusClass::Informational),
+            (&b"success_"[..], StatusClass::Success),
+            (&b"redirect_"[..], StatusClass::Redirect),
+            (&b"client_"[..], StatusClass::ClientError),
+            (&b"server_"[..], StatusClass::ServerError),
+        ] {
+            if token.starts_with(prefix) {
+                return class;
+            }
+        }
+
+        match token.first() {
+            Some(b'1') => StatusClass::Informational,
+            Some(b'2') => StatusClass::Success,
+            Some(b'3') => StatusClass::Redirect,
+            Some(b'4') => StatusClass::ClientError,
+            Some(b'5') => StatusClass::ServerError,
+            _ => StatusClass::ServerError,
+        }
+    }
+
+    /// The class an HTTP status code falls into, by its leading digit.
+    fn from_code(code: u16) -> StatusClass {
+        match code / 100 {
+            1 => StatusClass::Informational,
+            2 => StatusClass::Success,
+            3 => StatusClass::Redirect,
+            4 => StatusClass::ClientError,
+            _ => StatusClass::ServerError,
+        }
+    }
+
+    /// The default HTTP status code representing this class (100/200/300/
+    /// 400/500), used when an `Extension` token has no specific code of its
+    /// own.
+    fn default_http_code(&self) -> u16 {
+        match self {
+            StatusClass::Informational => 100,
+            StatusClass::Success => 200,
+            StatusClass::Redirect => 300,
+            StatusClass::ClientError => 400,
+            StatusClass::ServerError => 500,
+        }
+    }
+
+}
+
+impl std::fmt::Display for Method {
+    /// Emits the uppercase wire token (e.g. `READ`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.as_bytes()))
+    }
+}
+
+impl std::fmt::Display for StatusToken {
+    /// Emits the wire token (e.g. `not_found`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.as_bytes()))
+    }
+}
+
+impl StatusToken {
+    /// A canonical, human-readable reason phrase for this status, in the
+    /// style of HTTP's (e.g. `NotFound` -> "Not Found").
+    ///
+    /// `Extension` tokens have no specific phrase, so this returns their
+    /// inferred class's generic phrase instead.
+    pub fn reason_phrase(&self) -> &'static str {
+        match self {
+            StatusToken::Continue => "Continue",
+            StatusToken::SwitchingProtocols => "Switching Protocols",
+
+            StatusToken::Ok => "OK",
+            StatusToken::Created => "Created",
+            StatusToken::Accepted => "Accepted",
+            StatusToken::NoContent => "No Content",
+            StatusToken::PartialContent => "Partial Content",
+
+            StatusToken::MovedPermanently => "Moved Permanently",
+            StatusToken::Found => "Found",
+            StatusToken::SeeOther => "See Other",
+            StatusToken::NotModified => "Not Modified",
+            StatusToken::TemporaryRedirect => "Temporary Redirect",
+            StatusToken::PermanentRedirect => "Permanent Redirect",
+
+            StatusToken::BadRequest => "Bad Request",
+            StatusToken::Unauthorized => "Unauthorized",
+            StatusToken::Forbidden => "Forbidden",
+            StatusToken::NotFound => "Not Found",
+            StatusToken::MethodNotAllowed => "Method Not Allowed",
+            StatusToken::Conflict => "Conflict",
+            StatusToken::Gone => "Gone",
+            StatusToken::PayloadTooLarge => "Payload Too Large",
+            StatusToken::UnsupportedMediaType => "Unsupported Media Type",
+            StatusToken::TooManyRequests => "Too Many Requests",
+
+            StatusToken::InternalError => "Internal Server Error",
+            StatusToken::NotImplemented => "Not Implemented",
+            StatusToken::BadGateway => "Bad Gateway",
+            StatusToken::ServiceUnavailable => "Service Unavailable",
+            StatusToken::GatewayTimeout => "Gateway Timeout",
+
+            StatusToken::Extension(ext) => match ext.class() {
+                StatusClass::Informational => "Informational",
+                StatusClass::Success => "Success",
+                StatusClass::Redirect => "Redirect",
+                StatusClass::ClientError => "Client Error",
+                StatusClass::ServerError => "Server Error",
+            },
+        }
+    }
+}
+
+/// Compose a full NWEP request start-line (method, target, and version),
+/// so callers aren't hand-concatenating `as_bytes()` and separators.
+pub fn request_line(method: Method, target: &[u8]) -> Vec<u8> {
+    let mut line = Vec::with_capacity(method.as_bytes().len() + target.len() + 8);
+    line.extend_from_slice(method.as_bytes());
+    line.push(b' ');
+    line.extend_from_slice(target);
+    line.push(b' ');
+    line.extend_from_slice(b"NWEP/1");
+    line
+}
+
+/// Compose a full NWEP response start-line (version, status token, and
+/// reason phrase), so callers aren't hand-concatenating `as_bytes()` and
+/// separators.
+pub fn status_line(token: StatusToken) -> Vec<u8> {
+    let mut line = Vec::with_capacity(token.as_bytes().len() + 16);
+    line.extend_from_slice(b"NWEP/1 ");
+    line.extend_from_slice(token.as_bytes());
+    line.push(b' ');
+    line.extend_from_slice(token.reason_phrase().as_bytes());
+    line
+}
+
+impl StatusToken {
+    /// Whether this status is heuristically cacheable on its own, ignoring
+    /// the request method.
+    ///
+    /// True for the RFC-designated set of statuses a cache may store
+    /// without an explicit freshness directive: `Ok`, `NoContent`,
+    /// `PartialContent`, `MovedPermanently`, `NotFound`,
+    /// `MethodNotAllowed`, `Gone`, and `PermanentRedirect`.
+    pub fn is_cacheable_by_default(&self) -> bool {
+        matches!(
+            self,
+            StatusToken::Ok |
+                StatusToken::NoContent |
+                StatusToken::PartialContent |
+                StatusToken::MovedPermanently |
+                StatusToken::NotFound |
+                StatusToken::MethodNotAllowed |
+                StatusToken::Gone |
+                StatusToken::PermanentRedirect
+        )
+    }
+}
+
+/// The caching decision for a request/response pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CachePolicy {
+    /// Safe to store and reuse without revalidation.
+    Cacheable,
+    /// Storable, but must be revalidated against the origin once stale
+    /// (e.g. redirects, which can change target over time).
+    CacheableIfFresh,
+    /// Must not be cached.
+    NotCacheable,
+}
+
+/// Decide whether a response to `method` with `status` may be cached.
+///
+/// `Cacheable` only when the method is cacheable and the status is in the
+/// RFC-designated default-cacheable set; with a cacheable method, the
+/// remaining redirect statuses (those outside that set) are
+/// `CacheableIfFresh` since they're safe to store but should be
+/// revalidated once stale; everything else is `NotCacheable`. This gives
+/// the NWEP cache layer a single authoritative decision point instead of
+/// scattering status-code checks across callers.
+pub fn cache_policy(method: Method, status: StatusToken) -> CachePolicy {
+    if !method.is_cacheable() {
+        return CachePolicy::NotCacheable;
+    }
+
+    if status.is_cacheable_by_default() {
+        return CachePolicy::Cacheable;
+    }
+
+    if status.class() == StatusClass::Redirect {
+        return CachePolicy::CacheableIfFresh;
+    }
+
+    CachePolicy::NotCacheable
+}
+
+/// Maps an application error onto an NWEP response.
+///
+/// Mirrors ntex's `WebResponseError`: implement [`NwepResponseError::status_token`]
+/// once per error type and get a renderable response for free, rather than
+/// hand-matching every error into a status token at each call site.
+pub trait NwepResponseError: std::fmt::Debug {
+    /// The status token this error should be reported as.
+    ///
+    /// Defaults to `StatusToken::InternalError`, the safe fallback for an
+    /// error with no more specific mapping.
+    fn status_token(&self) -> StatusToken {
+        StatusToken::InternalError
+    }
+
+    /// Build a response for this error: a status token plus a
+    /// human-readable body derived from its `Debug` output.
+    fn error_response(&self) -> NwepErrorResponse {
+        NwepErrorResponse {
+            status: self.status_token(),
+            content_type: "text/plain",
+            body: format!("{:?}", self).into_bytes(),
+        }
+    }
+}
+
+/// A renderable NWEP response built from an application error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NwepErrorResponse {
+    /// The NWEP status token for this response.
+    pub status: StatusToken,
+    /// The response body's MIME type.
+    pub content_type: &'static str,
+    /// The response body.
+    pub body: Vec<u8>,
+}
+
+impl NwepResponseError for std::io::Error {
+    fn status_token(&self) -> StatusToken {
+        match self.kind() {
+            std::io::ErrorKind::NotFound => StatusToken::NotFound,
+            std::io::ErrorKind::PermissionDenied => StatusToken::Forbidden,
+            std::io::ErrorKind::TimedOut => StatusToken::GatewayTimeout,
+            std::io::ErrorKind::AlreadyExists => StatusToken::Conflict,
+            std::io::ErrorKind::InvalidInput |
+            std::io::ErrorKind::InvalidData => StatusToken::BadRequest,
+            _ => StatusToken::InternalError,
+        }
+    }
+}
+
+impl NwepResponseError for Error {
+    // No variant-specific mapping yet; every `Error` reports as
+    // `InternalError` until individual variants need finer-grained
+    // tokens, same as the trait's default.
+}
+
+/// Incremental parsing of NWEP start-lines and headers off the wire.
+///
+/// A server built on this crate receives a request (or a client a
+/// response) as whatever-sized chunks QUIC happens to deliver on a
+/// stream. [`RequestParser`] and [`ResponseParser`] consume those chunks
+/// as they arrive, buffering partial input across calls, instead of
+/// requiring the caller to assemble the whole message first.
+pub mod parser {
+    use super::Method;
+    use super::StatusToken;
+
+    /// Maximum length of a start line, in bytes.
+    const MAX_START_LINE_LEN: usize = 256;
+    /// Maximum number of headers a single message may carry.
+    const MAX_HEADER_COUNT: usize = 64;
+    /// Maximum total size of the header block, in bytes, counting each
+    /// parsed header's name and value plus the line still being read.
+    const MAX_HEADER_BLOCK_LEN: usize = 8192;
+
+    /// A single parsed `name: value` header line.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParsedHeader {
+        /// The header name.
+        pub name: Vec<u8>,
+        /// The header value.
+        pub value: Vec<u8>,
+    }
+
+    /// A fully parsed NWEP request start-line and headers.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParsedRequest {
+        /// The request method.
+        pub method: Method,
+        /// The request target (path).
+        pub target: Vec<u8>,
+        /// The request headers, in wire order.
+        pub headers: Vec<ParsedHeader>,
+    }
+
+    /// A fully parsed NWEP response start-line and headers.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParsedResponse {
+        /// The response status.
+        pub status: StatusToken,
+        /// The response headers, in wire order.
+        pub headers: Vec<ParsedHeader>,
+    }
+
+    /// The result of feeding a chunk to a parser.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum ParseOutcome<T> {
+        /// The message isn't complete yet; feed more bytes.
+        NeedMore,
+        /// The message is fully parsed.
+        Complete(T),
+        /// The input violates a protocol limit or is malformed. The
+        /// status token names the response a server should send back.
+        Error(StatusToken),
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum State {
+        Start,
+        Headers,
+        Done,
+    }
+
+    /// Find the index of the next line's trailing `\r\n`, if a full line is
+    /// buffered.
+    fn find_line(buf: &[u8]) -> Option<usize> {
+        buf.windows(2).position(|w| w == b"\r\n")
+    }
+
+    /// Parse as many complete `name: value\r\n` header lines as are
+    /// buffered, stopping at the blank line that ends the header block.
+    /// Returns `Ok(true)` once the blank line is consumed.
+    fn drain_headers(
+        buf: &mut Vec<u8>, headers: &mut Vec<ParsedHeader>,
+    ) -> Result<bool, StatusToken> {
+        loop {
+            let parsed_len: usize =
+                headers.iter().map(|h| h.name.len() + h.value.len()).sum();
+
+            let Some(line_len) = find_line(buf) else {
+                if parsed_len + buf.len() > MAX_HEADER_BLOCK_LEN {
+                    return Err(StatusToken::PayloadTooLarge);
+                }
+                return Ok(false);
+            };
+
+            if parsed_len + line_len > MAX_HEADER_BLOCK_LEN {
+                return Err(StatusToken::PayloadTooLarge);
+            }
+
+            let line: Vec<u8> = buf.drain(..line_len + 2).collect();
+            let line = &line[..line.len() - 2];
+
+            if line.is_empty() {
+                return Ok(true);
+            }
+
+            if headers.len() >= MAX_HEADER_COUNT {
+                return Err(StatusToken::PayloadTooLarge);
+            }
+
+            let mut parts = line.splitn(2, |&b| b == b':');
+            let name = parts.next().ok_or(StatusToken::BadRequest)?;
+            let value = parts.next().ok_or(StatusToken::BadRequest)?;
+            let value = value.strip_prefix(b" ").unwrap_or(value);
+
+            headers.push(ParsedHeader {
+                name: name.to_vec(),
+                value: value.to_vec(),
+            });
+        }
+    }
+
+    /// Incremental parser for an NWEP request start-line and headers.
+    ///
+    /// Feed it arbitrary-sized chunks of stream bytes via
+    /// [`RequestParser::advance`] as they arrive.
+    pub struct RequestParser {
+        state: State,
+        buf: Vec<u8>,
+        method: Option<Method>,
+        target: Vec<u8>,
+        headers: Vec<ParsedHeader>,
+    }
+
+    impl RequestParser {
+        /// Create a parser ready to receive the start of a request.
+        pub fn new() -> Self {
+            RequestParser {
+                state: State::Start,
+                buf: Vec::new(),
+                method: None,
+                target: Vec::new(),
+                headers: Vec::new(),
+            }
+        }
+
+        /// Feed the next chunk of bytes, advancing the state machine as
+        /// far as the buffered input allows.
+        pub fn advance(
+            &mut self, chunk: &[u8],
+        ) -> ParseOutcome<ParsedRequest> {
+            self.buf.extend_from_slice(chunk);
+
+            loop {
+                match self.state {
+                    State::Start => {
+                        let line_len = match find_line(&self.buf) {
+                            Some(len) => len,
+                            None => {
+                                if self.buf.len() > MAX_START_LINE_LEN {
+                                    return ParseOutcome::Error(
+                                        StatusToken::BadRequest,
+                                    );
+                                }
+                                return ParseOutcome::NeedMore;
+                            },
+                        };
+
+                        if line_len > MAX_START_LINE_LEN {
+                            return ParseOutcome::Error(
+                                StatusToken::BadRequest,
+                            );
+                        }
+
+                        let line: Vec<u8> =
+                            self.buf.drain(..line_len + 2).collect();
+                        let line = &line[..line.len() - 2];
+
+                        let mut parts = line.splitn(2, |&b| b == b' ');
+                        let method_token = match parts.next() {
+                            Some(t) if !t.is_empty() => t,
+                            _ =>
+                                return ParseOutcome::Error(
+                                    StatusToken::BadRequest,
+                                ),
+                        };
+
+                        let target = match parts.next() {
+                            Some(rest) => rest
+                                .split(|&b| b == b' ')
+                                .next()
+                                .unwrap_or(&[]),
+                            None =>
+                                return ParseOutcome::Error(
+                                    StatusToken::BadRequest,
+                                ),
+                        };
+
+                        self.method = match Method::from_bytes(method_token)
+                        {
+                            Ok(m) => Some(m),
+                            Err(_) =>
+                                return ParseOutcome::Error(
+                                    StatusToken::BadRequest,
+                                ),
+                        };
+                        self.target = target.to_vec();
+                        self.state = State::Headers;
+                    },
+
+                    State::Headers => {
+                        match drain_headers(&mut self.buf, &mut self.headers)
+                        {
+                            Ok(true) => self.state = State::Done,
+                            Ok(false) => return ParseOutcome::NeedMore,
+                            Err(status) =>
+                                return ParseOutcome::Error(status),
+                        }
+                    },
+
+                    State::Done => {
+                        return ParseOutcome::Complete(ParsedRequest {
+                            method: self.method.unwrap(),
+                            target: std::mem::take(&mut self.target),
+                            headers: std::mem::take(&mut self.headers),
+                        });
+                    },
+                }
+            }
+        }
+    }
+
+    impl Default for RequestParser {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Incremental parser for an NWEP response start-line and headers.
+    ///
+    /// Feed it arbitrary-sized chunks of stream bytes via
+    /// [`ResponseParser::advance`] as they arrive.
+    pub struct ResponseParser {
+        state: State,
+        buf: Vec<u8>,
+        status: Option<StatusToken>,
+        headers: Vec<ParsedHeader>,
+    }
+
+    impl ResponseParser {
+        /// Create a parser ready to receive the start of a response.
+        pub fn new() -> Self {
+            ResponseParser {
+                state: State::Start,
+                buf: Vec::new(),
+                status: None,
+                headers: Vec::new(),
+            }
+        }
+
+        /// Feed the next chunk of bytes, advancing the state machine as
+        /// far as the buffered input allows.
+        pub fn advance(
+            &mut self, chunk: &[u8],
+        ) -> ParseOutcome<ParsedResponse> {
+            self.buf.extend_from_slice(chunk);
+
+            loop {
+                match self.state {
+                    State::Start => {
+                        let line_len = match find_line(&self.buf) {
+                            Some(len) => len,
+                            None => {
+                                if self.buf.len() > MAX_START_LINE_LEN {
+                                    return ParseOutcome::Error(
+                                        StatusToken::BadRequest,
+                                    );
+                                }
+                                return ParseOutcome::NeedMore;
+                            },
+                        };
+
+                        if line_len > MAX_START_LINE_LEN {
+                            return ParseOutcome::Error(
+                                StatusToken::BadRequest,
+                            );
+                        }
+
+                        let line: Vec<u8> =
+                            self.buf.drain(..line_len + 2).collect();
+                        let line = &line[..line.len() - 2];
+
+                        // Skip the leading version token (`NWEP/1`) to get
+                        // to the status token.
+                        let status_token = line
+                            .split(|&b| b == b' ')
+                            .nth(1)
+                            .unwrap_or(&[]);
+
+                        if status_token.is_empty() {
+                            return ParseOutcome::Error(
+                                StatusToken::BadRequest,
+                            );
+                        }
+
+                        self.status =
+                            Some(StatusToken::from_bytes(status_token));
+                        self.state = State::Headers;
+                    },
+
+                    State::Headers => {
+                        match drain_headers(&mut self.buf, &mut self.headers)
+                        {
+                            Ok(true) => self.state = State::Done,
+                            Ok(false) => return ParseOutcome::NeedMore,
+                            Err(status) =>
+                                return ParseOutcome::Error(status),
+                        }
+                    },
+
+                    State::Done => {
+                        return ParseOutcome::Complete(ParsedResponse {
+                            status: self.status.unwrap(),
+                            headers: std::mem::take(&mut self.headers),
+                        });
+                    },
+                }
+            }
+        }
+    }
+
+    impl Default for ResponseParser {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use super::super::StatusClass;
+
+        #[test]
+        fn test_request_parser_single_chunk() {
+            let mut parser = RequestParser::new();
+            let outcome = parser.advance(
+                b"READ /index.html NWEP/1\r\nhost: example\r\n\r\n",
+            );
+
+            match outcome {
+                ParseOutcome::Complete(req) => {
+                    assert_eq!(req.method, Method::Read);
+                    assert_eq!(req.target, b"/index.html");
+                    assert_eq!(req.headers.len(), 1);
+                    assert_eq!(req.headers[0].name, b"host");
+                    assert_eq!(req.headers[0].value, b"example");
+                },
+                other => panic!("expected Complete, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_request_parser_byte_at_a_time() {
+            let mut parser = RequestParser::new();
+            let message =
+                b"WRITE /upload NWEP/1\r\ncontent-length: 4\r\n\r\n";
+
+            let mut outcome = ParseOutcome::NeedMore;
+            for byte in message {
+                outcome = parser.advance(&[*byte]);
+            }
+
+            match outcome {
+                ParseOutcome::Complete(req) => {
+                    assert_eq!(req.method, Method::Write);
+                    assert_eq!(req.target, b"/upload");
+                },
+                other => panic!("expected Complete, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_request_parser_bad_method() {
+            let mut parser = RequestParser::new();
+            let outcome = parser.advance(b"FETCH /x NWEP/1\r\n\r\n");
+            assert_eq!(outcome, ParseOutcome::Error(StatusToken::BadRequest));
+        }
+
+        #[test]
+        fn test_request_parser_start_line_too_long() {
+            let mut parser = RequestParser::new();
+            let target = vec![b'a'; MAX_START_LINE_LEN + 1];
+            let mut line = b"READ /".to_vec();
+            line.extend_from_slice(&target);
+            line.extend_from_slice(b" NWEP/1\r\n");
+
+            assert_eq!(
+                parser.advance(&line),
+                ParseOutcome::Error(StatusToken::BadRequest)
+            );
+        }
+
+        #[test]
+        fn test_request_parser_too_many_headers() {
+            let mut parser = RequestParser::new();
+            parser.advance(b"READ / NWEP/1\r\n");
+
+            for i in 0..MAX_HEADER_COUNT {
+                let header = format!("x-{}: v\r\n", i);
+                let outcome = parser.advance(header.as_bytes());
+                assert_eq!(outcome, ParseOutcome::NeedMore);
+            }
+
+            let outcome = parser.advance(b"x-overflow: v\r\n\r\n");
+            assert_eq!(
+                outcome,
+                ParseOutcome::Error(StatusToken::PayloadTooLarge)
+            );
+        }
+
+        #[test]
+        fn test_response_parser() {
+            let mut parser = ResponseParser::new();
+            let outcome = parser.advance(
+                b"NWEP/1 not_found Not Found\r\ncontent-length: 0\r\n\r\n",
+            );
+
+            match outcome {
+                ParseOutcome::Complete(resp) => {
+                    assert_eq!(resp.status, StatusToken::NotFound);
+                    assert_eq!(resp.headers.len(), 1);
+                },
+                other => panic!("expected Complete, got {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_response_parser_unknown_status_preserved() {
+            let mut parser = ResponseParser::new();
+            let outcome = parser
+                .advance(b"NWEP/1 client_quota_hit Quota Hit\r\n\r\n");
+
+            match outcome {
+                ParseOutcome::Complete(resp) => {
+                    assert_eq!(
+                        resp.status.class(),
+                        StatusClass::ClientError
+                    );
+                },
+                other => panic!("expected Complete, got {:?}", other),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -459,6 +1337,10 @@ mod tests {
         assert_eq!(Method::from_bytes(b"PROBE").unwrap(), Method::Probe);
         assert_eq!(Method::from_bytes(b"CONNECT").unwrap(), Method::Connect);
         assert_eq!(Method::from_bytes(b"TRACE").unwrap(), Method::Trace);
+        assert_eq!(
+            Method::from_bytes(b"SUBSCRIBE").unwrap(),
+            Method::Subscribe
+        );
 
         assert!(Method::from_bytes(b"GET").is_err());
         assert!(Method::from_bytes(b"POST").is_err());
@@ -476,6 +1358,10 @@ mod tests {
 
         assert!(!Method::Modify.is_safe());
         assert!(!Method::Modify.is_idempotent());
+
+        assert!(Method::Subscribe.is_safe());
+        assert!(!Method::Subscribe.is_idempotent());
+        assert!(!Method::Subscribe.is_cacheable());
     }
 
     #[test]
@@ -488,6 +1374,41 @@ mod tests {
         assert_eq!(Method::Write.to_http_method(), b"POST");
     }
 
+    #[test]
+    fn test_method_http_hint_round_trip() {
+        assert_eq!(
+            Method::from_http_method_with_hint(b"GET"),
+            Some((Method::Read, HttpMethodHint::Default))
+        );
+        assert_eq!(
+            Method::from_http_method_with_hint(b"HEAD"),
+            Some((Method::Read, HttpMethodHint::Head))
+        );
+        assert_eq!(
+            Method::from_http_method_with_hint(b"PUT"),
+            Some((Method::Write, HttpMethodHint::Put))
+        );
+
+        assert_eq!(
+            Method::Read.to_http_method_with_hint(HttpMethodHint::Head),
+            b"HEAD"
+        );
+        assert_eq!(
+            Method::Read.to_http_method_with_hint(HttpMethodHint::Default),
+            b"GET"
+        );
+        assert_eq!(
+            Method::Write.to_http_method_with_hint(HttpMethodHint::Put),
+            b"PUT"
+        );
+
+        // A hint that doesn't apply to `self` is ignored.
+        assert_eq!(
+            Method::Write.to_http_method_with_hint(HttpMethodHint::Head),
+            b"POST"
+        );
+    }
+
     #[test]
     fn test_status_parsing() {
         assert_eq!(StatusToken::from_bytes(b"ok"), StatusToken::Ok);
@@ -500,25 +1421,69 @@ mod tests {
             StatusToken::InternalError
         );
 
-        // Unknown tokens default to internal_error
+        // Unrecognized tokens are preserved as `Extension`, not collapsed.
         assert_eq!(
             StatusToken::from_bytes(b"unknown_status"),
+            StatusToken::Extension(ExtToken::new(b"unknown_status").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_status_extension_class_inference() {
+        // Keyword-prefixed tokens infer their class from the prefix.
+        assert_eq!(
+            StatusToken::from_bytes(b"client_quota_hit").class(),
+            StatusClass::ClientError
+        );
+        assert_eq!(
+            StatusToken::from_bytes(b"success_partial_sync").class(),
+            StatusClass::Success
+        );
+
+        // Numeric-hinted tokens infer their class from the leading digit.
+        assert_eq!(
+            StatusToken::from_bytes(b"499_client_closed").class(),
+            StatusClass::ClientError
+        );
+
+        // No hint at all falls back to ServerError.
+        assert_eq!(
+            StatusToken::from_bytes(b"teapot").class(),
+            StatusClass::ServerError
+        );
+    }
+
+    #[test]
+    fn test_status_extension_too_long_falls_back() {
+        let token = [b'x'; ExtToken::MAX_LEN + 1];
+        assert_eq!(
+            StatusToken::from_bytes(&token),
             StatusToken::InternalError
         );
     }
 
+    #[test]
+    fn test_status_extension_round_trip() {
+        let token = b"client_quota_hit";
+        let status = StatusToken::from_bytes(token);
+        assert_eq!(status.as_bytes(), token);
+    }
+
     #[test]
     fn test_status_http_conversion() {
         assert_eq!(StatusToken::Ok.to_http_code(), 200);
         assert_eq!(StatusToken::NotFound.to_http_code(), 404);
         assert_eq!(StatusToken::InternalError.to_http_code(), 500);
 
-        assert_eq!(StatusToken::from_http_code(200), Some(StatusToken::Ok));
-        assert_eq!(
-            StatusToken::from_http_code(404),
-            Some(StatusToken::NotFound)
-        );
-        assert_eq!(StatusToken::from_http_code(999), None);
+        assert_eq!(StatusToken::from_http_code(200), StatusToken::Ok);
+        assert_eq!(StatusToken::from_http_code(404), StatusToken::NotFound);
+
+        // Unmapped codes synthesize an Extension carrying the original
+        // code, so it round-trips exactly instead of collapsing to the
+        // class default.
+        let synthesized = StatusToken::from_http_code(499);
+        assert_eq!(synthesized.class(), StatusClass::ClientError);
+        assert_eq!(synthesized.to_http_code(), 499);
     }
 
     #[test]
@@ -530,4 +1495,72 @@ mod tests {
             StatusClass::ServerError
         );
     }
+
+    #[test]
+    fn test_response_error_io() {
+        let not_found =
+            std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert_eq!(not_found.status_token(), StatusToken::NotFound);
+
+        let denied =
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert_eq!(denied.status_token(), StatusToken::Forbidden);
+
+        let response = not_found.error_response();
+        assert_eq!(response.status, StatusToken::NotFound);
+        assert_eq!(response.content_type, "text/plain");
+    }
+
+    #[test]
+    fn test_cache_policy() {
+        assert_eq!(
+            cache_policy(Method::Read, StatusToken::Ok),
+            CachePolicy::Cacheable
+        );
+        assert_eq!(
+            cache_policy(Method::Read, StatusToken::NotFound),
+            CachePolicy::Cacheable
+        );
+        assert_eq!(
+            cache_policy(Method::Read, StatusToken::Found),
+            CachePolicy::CacheableIfFresh
+        );
+        assert_eq!(
+            cache_policy(Method::Read, StatusToken::InternalError),
+            CachePolicy::NotCacheable
+        );
+
+        // A non-cacheable method never yields a cacheable response, even
+        // with an otherwise-cacheable status.
+        assert_eq!(
+            cache_policy(Method::Write, StatusToken::Ok),
+            CachePolicy::NotCacheable
+        );
+    }
+
+    #[test]
+    fn test_reason_phrase_and_display() {
+        assert_eq!(StatusToken::NotFound.reason_phrase(), "Not Found");
+        assert_eq!(
+            StatusToken::TooManyRequests.reason_phrase(),
+            "Too Many Requests"
+        );
+        assert_eq!(format!("{}", StatusToken::NotFound), "not_found");
+        assert_eq!(format!("{}", Method::Read), "READ");
+
+        let ext = StatusToken::from_bytes(b"client_quota_hit");
+        assert_eq!(ext.reason_phrase(), "Client Error");
+    }
+
+    #[test]
+    fn test_start_lines() {
+        assert_eq!(
+            request_line(Method::Read, b"/index.html"),
+            b"READ /index.html NWEP/1"
+        );
+        assert_eq!(
+            status_line(StatusToken::NotFound),
+            b"NWEP/1 not_found Not Found"
+        );
+    }
 }
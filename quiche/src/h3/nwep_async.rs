@@ -0,0 +1,355 @@
+// Copyright (C) 2025, Ethan Pelletier
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are
+// met:
+//
+//     * Redistributions of source code must retain the above copyright notice,
+//       this list of conditions and the following disclaimer.
+//
+//     * Redistributions in binary form must reproduce the above copyright
+//       notice, this list of conditions and the following disclaimer in the
+//       documentation and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS
+// IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO,
+// THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR
+// PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR
+// CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL,
+// EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO,
+// PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Async, runtime-agnostic NWEP client API.
+//!
+//! `nwep-client.rs` shows the full event loop a caller has to write to use
+//! NWEP: register a `mio` socket, poll it, feed packets to `conn.recv`,
+//! drive `nwep_conn.poll` for events, and push packets back out with
+//! `conn.send`. That's fine for an example, but it means every real
+//! integration re-implements the same bookkeeping. This module hides it
+//! behind a small `async` surface so NWEP can be embedded in a Tokio or
+//! compio application directly.
+//!
+//! The only runtime-specific piece is [`AsyncSocket`]: implement it once
+//! per runtime (a thin wrapper around `tokio::net::UdpSocket` or a compio
+//! completion-based socket) and [`NwepClient`]/[`Connection`] work
+//! unchanged on top of it. `Connection::read` returns a [`ReadStream`]
+//! whose `next()` yields response body chunks as they arrive.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::task::Context;
+use std::task::Poll;
+
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+
+use super::Connection as NwepConnection;
+use super::Event;
+use super::Header;
+
+const MAX_DATAGRAM_SIZE: usize = 1350;
+
+/// A runtime-agnostic UDP socket.
+///
+/// Implement this once per async runtime. Both a Tokio `UdpSocket` and a
+/// compio completion-based socket fit this shape, and [`Connection`] never
+/// has to know which one is underneath.
+pub trait AsyncSocket: Unpin {
+    /// Send `buf` to `to`, yielding the number of bytes written.
+    fn poll_send_to(
+        &mut self, cx: &mut Context<'_>, buf: &[u8], to: SocketAddr,
+    ) -> Poll<std::io::Result<usize>>;
+
+    /// Receive a datagram into `buf`, yielding its length and sender.
+    fn poll_recv_from(
+        &mut self, cx: &mut Context<'_>, buf: &mut [u8],
+    ) -> Poll<std::io::Result<(usize, SocketAddr)>>;
+
+    /// The socket's local address, used as the QUIC `RecvInfo::to`.
+    fn local_addr(&self) -> std::io::Result<SocketAddr>;
+}
+
+/// Errors that can surface from the async NWEP client, on top of the
+/// transport and NWEP errors it wraps.
+#[derive(Debug)]
+pub enum AsyncError {
+    /// The UDP socket failed.
+    Io(std::io::Error),
+    /// The QUIC transport failed.
+    Quic(crate::Error),
+    /// The NWEP layer failed.
+    Nwep(super::Error),
+}
+
+impl From<std::io::Error> for AsyncError {
+    fn from(e: std::io::Error) -> Self {
+        AsyncError::Io(e)
+    }
+}
+
+impl From<crate::Error> for AsyncError {
+    fn from(e: crate::Error) -> Self {
+        AsyncError::Quic(e)
+    }
+}
+
+impl From<super::Error> for AsyncError {
+    fn from(e: super::Error) -> Self {
+        AsyncError::Nwep(e)
+    }
+}
+
+/// Builds [`Connection`]s that all share the same QUIC and NWEP
+/// configuration.
+pub struct NwepClient {
+    quic_config: crate::Config,
+    nwep_config: std::sync::Arc<super::Config>,
+}
+
+impl NwepClient {
+    /// Create a client that will use `quic_config` for the transport and
+    /// `nwep_config` for the NWEP layer on every connection it opens.
+    ///
+    /// `nwep_config` is kept behind an `Arc` so that opening many
+    /// connections only clones a reference count, not the `h3::Config`
+    /// itself -- which isn't guaranteed to implement `Clone`.
+    pub fn new(quic_config: crate::Config, nwep_config: super::Config) -> Self {
+        NwepClient {
+            quic_config,
+            nwep_config: std::sync::Arc::new(nwep_config),
+        }
+    }
+
+    /// Connect to `peer_addr` over `socket`, using `server_name` for SNI,
+    /// and drive the handshake until the connection can carry a request --
+    /// either because it is fully established, or because it is willing to
+    /// send 0-RTT early data on a resumed session.
+    pub async fn connect<S: AsyncSocket>(
+        &mut self, socket: S, local_addr: SocketAddr, peer_addr: SocketAddr,
+        server_name: Option<&str>,
+    ) -> Result<Connection<S>, AsyncError> {
+        let mut scid_bytes = [0; crate::MAX_CONN_ID_LEN];
+        SystemRandom::new()
+            .fill(&mut scid_bytes[..])
+            .map_err(|_| {
+                AsyncError::Io(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "failed to generate connection id",
+                ))
+            })?;
+        let scid = crate::ConnectionId::from_ref(&scid_bytes);
+
+        let quic = crate::connect(
+            server_name,
+            &scid,
+            local_addr,
+            peer_addr,
+            &mut self.quic_config,
+        )?;
+
+        let mut conn = Connection {
+            quic,
+            nwep: None,
+            nwep_config: self.nwep_config.clone(),
+            socket,
+            local_addr,
+            pending: HashMap::new(),
+            buf: vec![0; 65535],
+            out: vec![0; MAX_DATAGRAM_SIZE],
+        };
+
+        conn.flush().await?;
+
+        while !conn.quic.is_established() && !conn.quic.is_in_early_data() {
+            conn.recv_once().await?;
+            conn.flush().await?;
+        }
+
+        conn.nwep = Some(NwepConnection::with_transport(
+            &mut conn.quic,
+            &conn.nwep_config,
+        )?);
+
+        Ok(conn)
+    }
+}
+
+/// An established (or early-data-capable) NWEP connection driven by `poll`
+/// instead of a hand-rolled event loop.
+pub struct Connection<S: AsyncSocket> {
+    quic: crate::Connection,
+    nwep: Option<NwepConnection>,
+    nwep_config: std::sync::Arc<super::Config>,
+    socket: S,
+    local_addr: SocketAddr,
+
+    /// Response bytes buffered per stream id, for streams that have been
+    /// requested through [`Connection::read`] but not yet fully received.
+    pending: HashMap<u64, Vec<u8>>,
+
+    buf: Vec<u8>,
+    out: Vec<u8>,
+}
+
+impl<S: AsyncSocket> Connection<S> {
+    /// Issue a `READ` request for `path` and return a stream of the
+    /// response body as it arrives.
+    ///
+    /// The returned stream yields chunks as they're read off the wire; it
+    /// ends once the NWEP response reaches `Event::Finished`, or when the
+    /// stream is reset (`Event::Reset`), which ends it the same way.
+    pub fn read(
+        &mut self, authority: &str, path: &str,
+    ) -> Result<ReadStream<'_, S>, AsyncError> {
+        let nwep = self.nwep.as_mut().ok_or_else(|| {
+            AsyncError::Io(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "NWEP connection not established",
+            ))
+        })?;
+
+        let req = vec![
+            Header::new(b":method", b"READ"),
+            Header::new(b":scheme", b"web"),
+            Header::new(b":authority", authority.as_bytes()),
+            Header::new(b":path", path.as_bytes()),
+            Header::new(b"user-agent", b"nwep-async-client"),
+        ];
+
+        let stream_id = nwep.send_request(&mut self.quic, &req, true)?;
+
+        self.pending.insert(stream_id, Vec::new());
+
+        Ok(ReadStream {
+            conn: self,
+            stream_id,
+            done: false,
+        })
+    }
+
+    /// Wait for one datagram on the socket and feed it to the QUIC
+    /// connection, turning the socket's `WouldBlock` readiness into an
+    /// `.await` point.
+    ///
+    /// Nothing is sent here, and `self.quic.on_timeout()` is never called:
+    /// `AsyncSocket` has no timer, so this waits until a datagram arrives.
+    async fn recv_once(&mut self) -> Result<(), AsyncError> {
+        let (len, from) = std::future::poll_fn(|cx| {
+            self.socket.poll_recv_from(cx, &mut self.buf)
+        })
+        .await?;
+
+        let recv_info = crate::RecvInfo {
+            to: self.local_addr,
+            from,
+        };
+
+        self.quic.recv(&mut self.buf[..len], recv_info)?;
+
+        Ok(())
+    }
+
+    /// Flush every pending outgoing QUIC packet to the socket.
+    async fn flush(&mut self) -> Result<(), AsyncError> {
+        loop {
+            let (write, send_info) = match self.quic.send(&mut self.out) {
+                Ok(v) => v,
+                Err(crate::Error::Done) => break,
+                Err(e) => return Err(e.into()),
+            };
+
+            let mut written = 0;
+
+            while written < write {
+                written += std::future::poll_fn(|cx| {
+                    self.socket.poll_send_to(
+                        cx,
+                        &self.out[written..write],
+                        send_info.to,
+                    )
+                })
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Pump NWEP events until none are left or `stream_id` has finished or
+    /// been reset, buffering any body data that arrives for `stream_id`.
+    /// Returns `true` once the stream is done.
+    fn poll_stream_events(
+        &mut self, stream_id: u64,
+    ) -> Result<bool, AsyncError> {
+        let nwep = self.nwep.as_mut().unwrap();
+
+        loop {
+            match nwep.poll(&mut self.quic) {
+                Ok((id, Event::Headers { .. })) if id == stream_id => continue,
+
+                Ok((id, Event::Data)) if id == stream_id => {
+                    while let Ok(read) =
+                        nwep.recv_body(&mut self.quic, id, &mut self.buf)
+                    {
+                        self.pending
+                            .entry(id)
+                            .or_default()
+                            .extend_from_slice(&self.buf[..read]);
+                    }
+                },
+
+                Ok((id, Event::Finished)) if id == stream_id => return Ok(true),
+
+                Ok((id, Event::Reset(_))) if id == stream_id => return Ok(true),
+
+                // Events for other streams or connection-wide events: drop
+                // them here, they don't affect this read.
+                Ok(_) => continue,
+
+                Err(super::Error::Done) => return Ok(false),
+
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// A stream of response body chunks for one `READ` request, driven lazily
+/// through its async [`ReadStream::next`] method. It does not implement the
+/// `futures` `Stream` trait.
+pub struct ReadStream<'a, S: AsyncSocket> {
+    conn: &'a mut Connection<S>,
+    stream_id: u64,
+    done: bool,
+}
+
+impl<'a, S: AsyncSocket> ReadStream<'a, S> {
+    /// Await the next chunk of the response body, or `None` once the
+    /// response is complete.
+    pub async fn next(&mut self) -> Result<Option<Vec<u8>>, AsyncError> {
+        loop {
+            if let Some(buf) = self.conn.pending.get_mut(&self.stream_id) {
+                if !buf.is_empty() {
+                    return Ok(Some(std::mem::take(buf)));
+                }
+            }
+
+            if self.done {
+                self.conn.pending.remove(&self.stream_id);
+                return Ok(None);
+            }
+
+            self.done = self.conn.poll_stream_events(self.stream_id)?;
+
+            if !self.done {
+                self.conn.recv_once().await?;
+                self.conn.flush().await?;
+            }
+        }
+    }
+}